@@ -1,6 +1,6 @@
-import os, requests
-from typing import Optional, Dict
-from spider.spider_types import RequestParamsDict
+import os, json, requests
+from typing import Optional
+from spider.spider_types import RequestParamsDict, SignedUrlOptionsDict
 from spider.supabase_client import Supabase
 
 
@@ -53,7 +53,8 @@ class Spider:
         :param endpoint: The API endpoint to which the POST request is sent.
         :param data: The data (dictionary) to be sent in the POST request.
         :param stream: Boolean indicating if the response should be streamed.
-        :return: The JSON response or the raw response stream if stream is True.
+        :param content_type: The content type of the request. With 'application/jsonl' the body is parsed line by line.
+        :return: The JSON response, a list of JSON objects for 'application/jsonl', or the raw response stream if stream is True.
         """
         headers = self._prepare_headers(content_type)
         response = self._post_request(
@@ -62,22 +63,33 @@ class Spider:
         if stream:
             return response
         elif response.status_code == 200:
+            if content_type == "application/jsonl":
+                return [
+                    json.loads(line)
+                    for line in response.text.splitlines()
+                    if line.strip()
+                ]
             return response.json()
         else:
             self._handle_error(response, f"post to {endpoint}")
 
     def api_get(
-        self, endpoint: str, stream: bool, content_type: str = "application/json"
+        self,
+        endpoint: str,
+        stream: bool,
+        content_type: str = "application/json",
+        params: Optional[dict] = None,
     ):
         """
         Send a GET request to the specified endpoint.
 
         :param endpoint: The API endpoint from which to retrieve data.
+        :param params: Optional query parameters to include in the GET request.
         :return: The JSON decoded response.
         """
         headers = self._prepare_headers(content_type)
         response = self._get_request(
-            f"https://api.spider.cloud/{endpoint}", headers, stream
+            f"https://api.spider.cloud/v1/{endpoint}", headers, stream, params=params
         )
         if response.status_code == 200:
             return response.json()
@@ -256,7 +268,7 @@ class Spider:
     def create_signed_url(
         self,
         domain: Optional[str] = None,
-        options: Optional[Dict[str, int]] = None,
+        options: Optional[SignedUrlOptionsDict] = None,
         stream: Optional[bool] = True,
     ):
         """
@@ -328,14 +340,27 @@ class Spider:
         self,
         table: str,
         params: Optional[RequestParamsDict] = None,
+        limit: Optional[int] = None,
+        offset: Optional[int] = None,
     ):
         """
         Retrieve data from a specific table via GET request.
         :param table: The table name from which to retrieve data.
-        :param params: Optional parameters to modify the query.
+        :param params: Optional parameters to modify the query, sent as query parameters.
+        :param limit: Optional maximum number of records to return. Overrides a 'limit' in params.
+        :param offset: Optional number of records to skip for pagination. Overrides an 'offset' in params.
         :return: The JSON response from the server.
+        :raises ValueError: If a parameter has a dict or list value.
         """
-        return self.api_get(f"data/{table}", params)
+        query = dict(params or {})
+        if limit is not None:
+            query["limit"] = limit
+        if offset is not None:
+            query["offset"] = offset
+
+        return self.api_get(
+            f"data/{table}", stream=False, params=self._prepare_query_params(query)
+        )
 
     def data_delete(
         self,
@@ -357,11 +382,32 @@ class Spider:
             "User-Agent": f"Spider-Client/0.0.39",
         }
 
+    def _prepare_query_params(self, params: dict):
+        """
+        Encode parameters for a query string, which only carries scalar values.
+
+        Booleans are sent as 'true'/'false'. Dict and list values are rejected,
+        since requests would otherwise flatten them to their keys.
+
+        :raises ValueError: If a parameter has a dict or list value.
+        """
+        query = {}
+        for key, value in params.items():
+            if isinstance(value, bool):
+                query[key] = "true" if value else "false"
+            elif isinstance(value, (dict, list)):
+                raise ValueError(
+                    f"Parameter '{key}' cannot be sent as a query parameter"
+                )
+            else:
+                query[key] = value
+        return query
+
     def _post_request(self, url: str, data, headers, stream=False):
         return requests.post(url, headers=headers, json=data, stream=stream)
 
-    def _get_request(self, url: str, headers, stream=False):
-        return requests.get(url, headers=headers, stream=stream)
+    def _get_request(self, url: str, headers, stream=False, params=None):
+        return requests.get(url, headers=headers, params=params, stream=stream)
 
     def _delete_request(self, url: str, headers, params=None, stream=False):
         return requests.delete(url, headers=headers, params=params, stream=stream)
@@ -1,5 +1,5 @@
 from typing import Optional, Dict, Any
-from spider_types import RequestParamsDict
+from spider_types import RequestParamsDict, SignedUrlOptionsDict
 
 class Spider:
     api_key: str
@@ -13,7 +13,11 @@ class Spider:
         content_type: str = "application/json",
     ) -> Any: ...
     def api_get(
-        self, endpoint: str, stream: bool, content_type: str = "application/json"
+        self,
+        endpoint: str,
+        stream: bool,
+        content_type: str = "application/json",
+        params: Optional[Dict[str, Any]] = None,
     ) -> Any: ...
     def api_delete(
         self, endpoint: str, stream: bool, content_type: str = "application/json"
@@ -88,14 +92,15 @@ class Spider:
     def create_signed_url(
         self,
         domain: Optional[str] = None,
-        page: Optional[int] = None,
-        limit: Optional[int] = None,
+        options: Optional[SignedUrlOptionsDict] = None,
         stream: Optional[bool] = True,
     ) -> Any: ...
     def data_get(
         self,
         table: str,
         params: Optional[RequestParamsDict] = None,
+        limit: Optional[int] = None,
+        offset: Optional[int] = None,
     ) -> Any: ...
     def data_delete(
         self,
@@ -105,11 +110,16 @@ class Spider:
     def _prepare_headers(
         self, content_type: str = "application/json"
     ) -> Dict[str, str]: ...
+    def _prepare_query_params(self, params: Dict[str, Any]) -> Dict[str, Any]: ...
     def _post_request(
         self, url: str, data: Any, headers: Dict[str, str], stream: bool = False
     ) -> Any: ...
     def _get_request(
-        self, url: str, headers: Dict[str, str], stream: bool = False
+        self,
+        url: str,
+        headers: Dict[str, str],
+        stream: bool = False,
+        params: Optional[Dict[str, Any]] = None,
     ) -> Any: ...
     def _delete_request(
         self, url: str, headers: Dict[str, str], stream: bool = False
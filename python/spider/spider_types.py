@@ -36,3 +36,9 @@ class RequestParamsDict(TypedDict, total=False):
     request_timeout: Optional[int]
     run_in_background: Optional[bool]
     skip_config_checks: Optional[bool]
+
+
+class SignedUrlOptionsDict(TypedDict, total=False):
+    page: Optional[int]
+    limit: Optional[int]
+    expiresIn: Optional[int]
@@ -1,4 +1,5 @@
-import pytest, time, os
+import pytest, time, os, requests
+from urllib.parse import parse_qs, urlparse
 from spider.spider import Spider
 from spider.spider_types import RequestParamsDict
 from dotenv import load_dotenv
@@ -37,6 +38,26 @@ def test_crawl_url(spider, url, params):
     assert response is not None
 
 
+def test_crawl_url_parses_jsonl(monkeypatch):
+    class FakeResponse:
+        status_code = 200
+        text = '{"url": "https://example.com"}\n{"url": "https://example.com/about"}\n'
+
+    monkeypatch.setattr(
+        "spider.spider.requests.post", lambda *args, **kwargs: FakeResponse()
+    )
+
+    client = Spider(api_key="test")
+    response = client.crawl_url(
+        "https://example.com", content_type="application/jsonl"
+    )
+
+    assert response == [
+        {"url": "https://example.com"},
+        {"url": "https://example.com/about"},
+    ]
+
+
 def test_links(spider, url, params):
     response = spider.links(url, params=params)
     assert response is not None
@@ -91,6 +112,45 @@ def test_data_get(spider, url, params):
     assert response is not None
 
 
+def test_data_get_sends_query_params(monkeypatch):
+    captured = {}
+
+    class FakeResponse:
+        status_code = 200
+
+        def json(self):
+            return {"data": []}
+
+    def fake_get(url, headers=None, params=None, stream=False):
+        captured["url"] = requests.Request("GET", url, params=params).prepare().url
+        return FakeResponse()
+
+    monkeypatch.setattr("spider.spider.requests.get", fake_get)
+
+    client = Spider(api_key="test")
+    client.data_get(
+        "websites",
+        params={"domain": "example.com", "cache": True, "limit": 1},
+        limit=5,
+        offset=10,
+    )
+
+    url = urlparse(captured["url"])
+    assert url.path == "/v1/data/websites"
+    assert parse_qs(url.query) == {
+        "domain": ["example.com"],
+        "cache": ["true"],
+        "limit": ["5"],
+        "offset": ["10"],
+    }
+
+
+def test_data_get_rejects_nested_params():
+    client = Spider(api_key="test")
+    with pytest.raises(ValueError, match="chunking_alg"):
+        client.data_get("websites", params={"chunking_alg": {"type": "bywords"}})
+
+
 def test_client_auth(spider):
     spider.init_supabase()
     email_pass = {